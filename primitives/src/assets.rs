@@ -1,7 +1,14 @@
 use crate::{AccountId, AssetId};
 
-use frame_support::ensure;
-use sp_std::marker::PhantomData;
+use frame_support::{
+	ensure,
+	traits::{
+		fungibles::{metadata::Inspect as MetadataInspect, Mutate},
+		Get,
+	},
+};
+use sp_core::H160;
+use sp_std::{marker::PhantomData, vec::Vec};
 
 use pallet_assets::AssetsCallback;
 use pallet_evm_precompile_assets_erc20::AddressToAssetId;
@@ -16,18 +23,25 @@ pub const EVM_REVERT_CODE: &[u8] = &[0x60, 0x00, 0x60, 0x00, 0xfd];
 ///
 /// It is important to note that if the dedicated asset EVM address is already taken, asset creation should fail.
 /// After asset has been destroyed, it is also safe to remove the revert code and free the address for future usage.
-pub struct EvmRevertCodeHandler<A, R>(PhantomData<(A, R)>);
-impl<A, R> AssetsCallback<AssetId, AccountId> for EvmRevertCodeHandler<A, R>
+///
+/// `Mint` optionally credits an account with an initial balance of every created asset, see `MintTo`. Defaults to
+/// no mint.
+pub struct EvmRevertCodeHandler<A, R, Mint = ()>(PhantomData<(A, R, Mint)>);
+impl<A, R, Mint> AssetsCallback<AssetId, AccountId> for EvmRevertCodeHandler<A, R, Mint>
 where
 	A: AddressToAssetId<AssetId>,
 	R: pallet_evm::Config,
+	Mint: InitialMint,
 {
 	fn created(id: &AssetId, _: &AccountId) -> Result<(), ()> {
 		let address = A::asset_id_to_address(*id);
+		// Addresses that don't map back to the asset (e.g. outside of the reserved range) aren't usable.
+		ensure!(A::address_to_asset_id(address) == Some(*id), ());
 		// In case of collision, we need to cancel the asset creation.
 		ensure!(!pallet_evm::AccountCodes::<R>::contains_key(&address), ());
 		pallet_evm::AccountCodes::<R>::insert(address, EVM_REVERT_CODE.to_vec());
-		Ok(())
+
+		Mint::mint(id)
 	}
 
 	fn destroyed(id: &AssetId) -> Result<(), ()> {
@@ -37,6 +51,112 @@ where
 	}
 }
 
+impl<A, R, Mint> EvmRevertCodeHandler<A, R, Mint>
+where
+	A: AddressToAssetId<AssetId>,
+	R: pallet_evm::Config + pallet_assets::Config<AssetId = AssetId>,
+{
+	/// Decimals reported by the asset's `ERC20-like` interface, `None` if the asset isn't available to the EVM or
+	/// has no metadata.
+	pub fn evm_decimals(id: &AssetId) -> Option<u8> {
+		let address = A::asset_id_to_address(*id);
+		if !pallet_evm::AccountCodes::<R>::contains_key(&address) ||
+			!pallet_assets::Metadata::<R>::contains_key(id)
+		{
+			return None
+		}
+		Some(<pallet_assets::Pallet<R> as MetadataInspect<R::AccountId>>::decimals(*id))
+	}
+}
+
+/// Initial balance minted by `EvmRevertCodeHandler` once an asset's revert code is registered.
+pub trait InitialMint {
+	/// Mint the initial balance of the newly created asset `id`.
+	fn mint(id: &AssetId) -> Result<(), ()>;
+}
+
+impl InitialMint for () {
+	fn mint(_: &AssetId) -> Result<(), ()> {
+		Ok(())
+	}
+}
+
+/// Mints the `(account, amount)` returned by `Target`, if any, of every created asset of `pallet_assets`.
+///
+/// Useful to pre-seed a treasury or bridge account so that the `ERC20-like` interface shows it immediately.
+/// Minting is fallible: if the amount is below the asset's minimum balance, or the asset isn't sufficient and
+/// the account has no provider (e.g. no native balance), the asset creation fails with `CallbackFailed`.
+pub struct MintTo<R, Target>(PhantomData<(R, Target)>);
+impl<R, Target> InitialMint for MintTo<R, Target>
+where
+	R: pallet_assets::Config<AssetId = AssetId>,
+	Target: Get<Option<(R::AccountId, R::Balance)>>,
+{
+	fn mint(id: &AssetId) -> Result<(), ()> {
+		if let Some((who, amount)) = Target::get() {
+			<pallet_assets::Pallet<R> as Mutate<R::AccountId>>::mint_into(*id, &who, amount)
+				.map_err(|_| ())?;
+		}
+		Ok(())
+	}
+}
+
+/// Restricts the `A` mapping to the EVM addresses within the inclusive `Range`.
+///
+/// Addresses outside of the range don't map to any asset, so combined with `EvmRevertCodeHandler` assets
+/// whose address falls outside of it can't be created. This keeps asset addresses from clashing with
+/// deployed contracts.
+pub struct AddressRangeToAssetId<A, Range>(PhantomData<(A, Range)>);
+impl<A, Range> AddressToAssetId<AssetId> for AddressRangeToAssetId<A, Range>
+where
+	A: AddressToAssetId<AssetId>,
+	Range: Get<(H160, H160)>,
+{
+	fn address_to_asset_id(address: H160) -> Option<AssetId> {
+		let (first, last) = Range::get();
+		if address < first || address > last {
+			return None
+		}
+		A::address_to_asset_id(address)
+	}
+
+	fn asset_id_to_address(asset_id: AssetId) -> H160 {
+		A::asset_id_to_address(asset_id)
+	}
+}
+
+/// Mismatch between an asset's state and the revert code registered at its EVM address.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuditFinding {
+	/// A live asset has no revert code, so its precompile isn't functional.
+	MissingCode(AssetId),
+	/// A dead asset still has revert code, so its address isn't free for future usage.
+	OrphanCode(AssetId),
+}
+
+/// Checks the invariant that every live asset has revert code registered and no dead asset does.
+///
+/// `assets` yields the asset ids to audit along with whether each asset is alive.
+/// Returns the mismatches found, in iteration order.
+pub fn audit_revert_codes<R, A, I>(assets: I) -> Vec<AuditFinding>
+where
+	R: pallet_evm::Config,
+	A: AddressToAssetId<AssetId>,
+	I: IntoIterator<Item = (AssetId, bool)>,
+{
+	assets
+		.into_iter()
+		.filter_map(|(id, alive)| {
+			let has_code = pallet_evm::AccountCodes::<R>::contains_key(A::asset_id_to_address(id));
+			match (alive, has_code) {
+				(true, false) => Some(AuditFinding::MissingCode(id)),
+				(false, true) => Some(AuditFinding::OrphanCode(id)),
+				_ => None,
+			}
+		})
+		.collect()
+}
+
 // #[cfg(feature = "runtime-benchmarks")]
 // /// Benchmark helper for `pallet-assets`.
 // pub struct AssetsBenchmarkHelper;
@@ -47,3 +167,161 @@ where
 //         AssetId::from(id).into()
 //     }
 // }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::*;
+	use frame_support::{assert_noop, assert_ok, traits::Currency};
+	use pallet_evm_precompile_assets_erc20::Action;
+	use precompile_utils::{testing::PrecompileTesterExt, EvmDataWriter};
+
+	type Handler = EvmRevertCodeHandler<Test, Test>;
+
+	#[test]
+	fn evm_decimals_match_native_decimals() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, ALICE, true, 1));
+			assert_eq!(Handler::evm_decimals(&1), None);
+
+			assert_ok!(Assets::force_set_metadata(
+				RuntimeOrigin::root(),
+				1,
+				b"Riel".to_vec(),
+				b"KHR".to_vec(),
+				12,
+				false
+			));
+			assert_eq!(<Assets as MetadataInspect<AccountId>>::decimals(1), 12);
+			assert_eq!(Handler::evm_decimals(&1), Some(12));
+
+			// Metadata without a name or symbol still has decimals.
+			assert_ok!(Assets::force_set_metadata(
+				RuntimeOrigin::root(),
+				1,
+				Vec::new(),
+				Vec::new(),
+				12,
+				false
+			));
+			assert_eq!(Handler::evm_decimals(&1), Some(12));
+
+			// The precompile reports the same decimals to the EVM.
+			PrecompilesValue::get()
+				.prepare_test(
+					H160::repeat_byte(0xAA),
+					Test::asset_id_to_address(1),
+					EvmDataWriter::new_with_selector(Action::Decimals).build(),
+				)
+				.execute_returns(EvmDataWriter::new().write(12u8).build());
+		});
+	}
+
+	#[test]
+	fn evm_decimals_require_revert_code() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, ALICE, true, 1));
+			assert_ok!(Assets::force_set_metadata(
+				RuntimeOrigin::root(),
+				1,
+				b"Riel".to_vec(),
+				b"KHR".to_vec(),
+				12,
+				false
+			));
+
+			pallet_evm::AccountCodes::<Test>::remove(Test::asset_id_to_address(1));
+			assert_eq!(Handler::evm_decimals(&1), None);
+		});
+	}
+
+	#[test]
+	fn audit_reports_mismatches_in_iteration_order() {
+		new_test_ext().execute_with(|| {
+			// Assets 1 and 2 are live, but asset 2 lost its revert code.
+			assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, ALICE, true, 1));
+			assert_ok!(Assets::force_create(RuntimeOrigin::root(), 2, ALICE, true, 1));
+			pallet_evm::AccountCodes::<Test>::remove(Test::asset_id_to_address(2));
+			// Asset 3 is dead, but its revert code is still registered.
+			pallet_evm::AccountCodes::<Test>::insert(
+				Test::asset_id_to_address(3),
+				EVM_REVERT_CODE.to_vec(),
+			);
+
+			assert_eq!(
+				audit_revert_codes::<Test, Test, _>([(3, false), (1, true), (2, true), (4, false)]),
+				vec![AuditFinding::OrphanCode(3), AuditFinding::MissingCode(2)],
+			);
+			assert_eq!(
+				audit_revert_codes::<Test, Test, _>([(2, true), (3, false)]),
+				vec![AuditFinding::MissingCode(2), AuditFinding::OrphanCode(3)],
+			);
+			assert!(audit_revert_codes::<Test, Test, _>([(1, true), (4, false)]).is_empty());
+		});
+	}
+
+	frame_support::parameter_types! {
+		pub AssetRange: (H160, H160) =
+			(Test::asset_id_to_address(10), Test::asset_id_to_address(20));
+	}
+
+	type RangedAddress = AddressRangeToAssetId<Test, AssetRange>;
+
+	#[test]
+	fn address_range_maps_in_range_addresses_only() {
+		for id in [10, 15, 20] {
+			assert_eq!(RangedAddress::address_to_asset_id(Test::asset_id_to_address(id)), Some(id));
+		}
+		for id in [0, 9, 21, AssetId::MAX] {
+			assert_eq!(RangedAddress::address_to_asset_id(Test::asset_id_to_address(id)), None);
+		}
+		assert_eq!(RangedAddress::address_to_asset_id(H160::repeat_byte(0xAA)), None);
+		assert_eq!(RangedAddress::asset_id_to_address(21), Test::asset_id_to_address(21));
+	}
+
+	#[test]
+	fn created_rejects_assets_outside_of_the_range() {
+		new_test_ext().execute_with(|| {
+			type RangedHandler = EvmRevertCodeHandler<RangedAddress, Test>;
+
+			assert_eq!(RangedHandler::created(&15, &ALICE), Ok(()));
+			assert!(pallet_evm::AccountCodes::<Test>::contains_key(Test::asset_id_to_address(15)));
+
+			assert_eq!(RangedHandler::created(&21, &ALICE), Err(()));
+			assert!(!pallet_evm::AccountCodes::<Test>::contains_key(Test::asset_id_to_address(21)));
+		});
+	}
+
+	#[test]
+	fn created_registers_code_and_mints_initial_balance() {
+		new_test_ext().execute_with(|| {
+			InitialMintTarget::set(&Some((BOB, 100)));
+
+			assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, ALICE, true, 1));
+			assert!(pallet_evm::AccountCodes::<Test>::contains_key(Test::asset_id_to_address(1)));
+			assert_eq!(Assets::balance(1, BOB), 100);
+		});
+	}
+
+	#[test]
+	fn failed_initial_mint_fails_asset_creation() {
+		new_test_ext().execute_with(|| {
+			// Below the minimum balance of the asset.
+			InitialMintTarget::set(&Some((BOB, 5)));
+			assert_noop!(
+				Assets::force_create(RuntimeOrigin::root(), 1, ALICE, true, 10),
+				pallet_assets::Error::<Test>::CallbackFailed
+			);
+
+			// A non-sufficient asset needs an account that already exists.
+			InitialMintTarget::set(&Some((BOB, 100)));
+			assert_noop!(
+				Assets::force_create(RuntimeOrigin::root(), 1, ALICE, false, 1),
+				pallet_assets::Error::<Test>::CallbackFailed
+			);
+			Balances::make_free_balance_be(&BOB, 1);
+			assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, ALICE, false, 1));
+			assert_eq!(Assets::balance(1, BOB), 100);
+		});
+	}
+}
@@ -19,6 +19,8 @@
 //! Core Selendra types.
 
 pub mod assets;
+#[cfg(test)]
+mod mock;
 
 use sp_runtime::{
 	generic,
@@ -89,3 +91,14 @@ pub type AssetId = u128;
 
 /// Digest item type.
 pub type DigestItem = generic::DigestItem;
+
+sp_api::decl_runtime_apis! {
+	/// API to query the vesting state of an account.
+	pub trait VestingApi {
+		/// The soonest future block at which the claimable vested amount of `who` increases,
+		/// together with the amount unlocked at that block.
+		///
+		/// Returns `None` if the account has no remaining locked funds.
+		fn vesting_next_unlock(who: AccountId) -> Option<(BlockNumber, Balance)>;
+	}
+}
@@ -17,7 +17,8 @@
 //! Auxiliary `struct`/`enum`s for selendra runtime.
 //!
 use crate::NegativeImbalance;
-use frame_support::traits::{Currency, Imbalance, OnUnbalanced};
+use frame_support::traits::{Currency, Get, Imbalance, OnUnbalanced};
+use sp_runtime::Perbill;
 
 /// Logic for the author to get a portion of fees.
 pub struct ToAuthor<R>(sp_std::marker::PhantomData<R>);
@@ -57,3 +58,118 @@ where
 		}
 	}
 }
+
+/// Fee handler burning a `BurnRatio` share of the fees, reducing the total issuance.
+///
+/// The remaining fees are split evenly between `Treasury` and `Author`, while tips go to
+/// `Author` in full.
+pub struct SplitFeesWithBurn<R, Treasury, Author, BurnRatio>(
+	sp_std::marker::PhantomData<(R, Treasury, Author, BurnRatio)>,
+);
+impl<R, Treasury, Author, BurnRatio> OnUnbalanced<NegativeImbalance<R>>
+	for SplitFeesWithBurn<R, Treasury, Author, BurnRatio>
+where
+	R: pallet_balances::Config,
+	Treasury: OnUnbalanced<NegativeImbalance<R>>,
+	Author: OnUnbalanced<NegativeImbalance<R>>,
+	BurnRatio: Get<Perbill>,
+{
+	fn on_unbalanceds<B>(mut fees_then_tips: impl Iterator<Item = NegativeImbalance<R>>) {
+		if let Some(fees) = fees_then_tips.next() {
+			let to_burn = BurnRatio::get() * fees.peek();
+			let (burn, rest) = fees.split(to_burn);
+			// for the remaining fees, 50% to treasury, 50% to author
+			let (to_treasury, mut to_author) = rest.ration(50, 50);
+			if let Some(tips) = fees_then_tips.next() {
+				// for tips, 100% to author
+				tips.merge_into(&mut to_author);
+			}
+			<() as OnUnbalanced<_>>::on_unbalanced(burn);
+			Treasury::on_unbalanced(to_treasury);
+			Author::on_unbalanced(to_author);
+		}
+	}
+}
+
+/// Amounts a fee handler is expected to burn and to credit to the treasury and the author.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FeeSplit<Balance> {
+	pub burnt: Balance,
+	pub to_treasury: Balance,
+	pub to_author: Balance,
+}
+
+/// Hands a `fee` and a `tip` to the fee handler `Handler` of runtime `R` and asserts that they end
+/// up split as `expected` between the burn, the `treasury` and the `author` accounts.
+///
+/// Must be called within the externalities of `R`, with `author` being the block author known to
+/// `R`, so that each runtime can verify its own `OnUnbalanced` wiring.
+#[cfg(feature = "std")]
+pub fn assert_fee_split<R, Handler>(
+	fee: R::Balance,
+	tip: R::Balance,
+	treasury: &R::AccountId,
+	author: &R::AccountId,
+	expected: FeeSplit<R::Balance>,
+) where
+	R: pallet_balances::Config,
+	Handler: OnUnbalanced<NegativeImbalance<R>>,
+{
+	type Balances<R> = pallet_balances::Pallet<R>;
+
+	let treasury_before = Balances::<R>::free_balance(treasury);
+	let author_before = Balances::<R>::free_balance(author);
+	let fee = Balances::<R>::issue(fee);
+	let tip = Balances::<R>::issue(tip);
+	let issuance_before = Balances::<R>::total_issuance();
+
+	Handler::on_unbalanceds(vec![fee, tip].into_iter());
+
+	let actual = FeeSplit {
+		burnt: issuance_before - Balances::<R>::total_issuance(),
+		to_treasury: Balances::<R>::free_balance(treasury) - treasury_before,
+		to_author: Balances::<R>::free_balance(author) - author_before,
+	};
+	assert_eq!(actual, expected, "fees and tips were not split as expected");
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::*;
+	use frame_support::parameter_types;
+
+	parameter_types! {
+		pub const HalfBurn: Perbill = Perbill::from_percent(50);
+	}
+
+	#[test]
+	fn split_fees_with_burn_reduces_issuance() {
+		new_test_ext().execute_with(|| {
+			// Half of the fee is burnt and the rest is shared between the treasury and the author,
+			// who also gets 100% of the tip.
+			assert_fee_split::<Test, SplitFeesWithBurn<Test, Treasury, ToAuthor<Test>, HalfBurn>>(
+				100,
+				20,
+				&Treasury::account_id(),
+				&TEST_ACCOUNT,
+				FeeSplit { burnt: 50, to_treasury: 25, to_author: 45 },
+			);
+		});
+	}
+
+	#[test]
+	fn deal_with_fees_burns_and_rewards_author() {
+		new_test_ext().execute_with(|| {
+			// For both fees and tips, 70% is burnt and 30% goes to the author.
+			assert_fee_split::<Test, DealWithFees<Test>>(
+				100,
+				20,
+				&Treasury::account_id(),
+				&TEST_ACCOUNT,
+				FeeSplit { burnt: 84, to_treasury: 0, to_author: 36 },
+			);
+		});
+	}
+}
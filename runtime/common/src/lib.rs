@@ -20,15 +20,22 @@
 
 pub mod elections;
 pub mod impls;
+pub mod origin;
+pub mod treasury_burn;
+pub mod vesting;
+
+#[cfg(test)]
+mod mock;
 
 use frame_support::{
+	dispatch::DispatchClass,
 	parameter_types,
 	traits::{ConstU32, Currency},
 	weights::{constants::WEIGHT_REF_TIME_PER_SECOND, Weight},
 };
 use frame_system::limits;
 use primitives::{Balance, BlockNumber};
-use sp_runtime::{FixedPointNumber, Perbill, Perquintill};
+use sp_runtime::{FixedPointNumber, PerThing, Perbill, Perquintill};
 use static_assertions::const_assert;
 
 pub use pallet_balances::Call as BalancesCall;
@@ -42,6 +49,7 @@ pub use sp_runtime::BuildStorage;
 
 /// Implementations of some helper traits passed into runtime modules as associated types.
 pub use impls::ToAuthor;
+pub use origin::*;
 
 pub type NegativeImbalance<T> = <pallet_balances::Pallet<T> as Currency<
 	<T as frame_system::Config>::AccountId,
@@ -58,6 +66,11 @@ pub const NORMAL_DISPATCH_RATIO: Perbill = Perbill::from_percent(75);
 pub const MAXIMUM_BLOCK_WEIGHT: Weight =
 	Weight::from_parts(WEIGHT_REF_TIME_PER_SECOND.saturating_mul(2), u64::MAX);
 
+/// The portion of the block reserved for `Operational` extrinsics on top of what they can fill.
+/// By default, this is what `NORMAL_DISPATCH_RATIO` leaves over.
+pub const OPERATIONAL_RESERVE_RATIO: Perbill =
+	Perbill::from_parts(<Perbill as PerThing>::ACCURACY - NORMAL_DISPATCH_RATIO.deconstruct());
+
 const_assert!(NORMAL_DISPATCH_RATIO.deconstruct() >= AVERAGE_ON_INITIALIZE_RATIO.deconstruct());
 
 // Common constants used in all runtimes.
@@ -71,7 +84,7 @@ parameter_types! {
 	pub AdjustmentVariable: Multiplier = Multiplier::saturating_from_rational(75, 1000_000);
 	/// Minimum amount of the multiplier. This value cannot be too low. A test case should ensure
 	/// that combined with `AdjustmentVariable`, we can recover from the minimum.
-	/// See `multiplier_can_grow_from_zero`.
+	/// See `assert_multiplier_recovers_from_min`.
 	pub MinimumMultiplier: Multiplier = Multiplier::saturating_from_rational(1, 10u128);
 	/// The maximum amount of the multiplier.
 	pub MaximumMultiplier: Multiplier = Bounded::max_value();
@@ -90,19 +103,111 @@ pub type SlowAdjustingFeeUpdate<R> = TargetedFeeAdjustment<
 	MaximumMultiplier,
 >;
 
+/// Asserts that the fee multiplier update `Update` of runtime `R` recovers from `minimum`.
+///
+/// An empty block must leave the multiplier at `minimum`, after which each of `full_blocks`
+/// full blocks must grow it monotonically without exceeding `maximum`. If the minimum is too small
+/// compared to the adjustment variable, the multiplier never changes and fees are stuck forever.
+///
+/// Must be called within the externalities of `R`, so that each runtime can run it against its
+/// own constants.
+#[cfg(feature = "std")]
+pub fn assert_multiplier_recovers_from_min<R, Update>(
+	minimum: Multiplier,
+	maximum: Multiplier,
+	full_blocks: u32,
+) where
+	R: frame_system::Config,
+	Update: sp_runtime::traits::Convert<Multiplier, Multiplier>,
+{
+	use frame_support::traits::Get;
+
+	frame_system::Pallet::<R>::set_block_consumed_resources(Weight::zero(), 0);
+	let mut multiplier = Update::convert(minimum);
+	assert_eq!(multiplier, minimum, "an empty block moved the multiplier off the minimum");
+
+	let block_weights = R::BlockWeights::get();
+	let full_block = block_weights
+		.get(DispatchClass::Normal)
+		.max_total
+		.unwrap_or(block_weights.max_block);
+	frame_system::Pallet::<R>::set_block_consumed_resources(full_block, 0);
+	for block in 0..full_blocks {
+		let next = Update::convert(multiplier);
+		assert!(
+			next > multiplier || next == maximum,
+			"multiplier did not grow at full block {}: {:?} !> {:?}",
+			block,
+			next,
+			multiplier,
+		);
+		assert!(next <= maximum, "multiplier exceeded the maximum: {:?} > {:?}", next, maximum);
+		multiplier = next;
+	}
+}
+
+/// Builds the block weights of a runtime from its base block and base extrinsic weights.
+///
+/// `Normal` extrinsics may fill the block up to `NORMAL_DISPATCH_RATIO`, while `Operational` ones
+/// may fill it entirely, plus the remainder as reserved space.
+pub fn build_block_weights(base_block: Weight, base_extrinsic: Weight) -> limits::BlockWeights {
+	build_block_weights_with_reserve(base_block, base_extrinsic, OPERATIONAL_RESERVE_RATIO)
+}
+
+/// Like [`build_block_weights`], but reserving `operational_reserve` of the block for
+/// `Operational` extrinsics.
+///
+/// `Normal` extrinsics are capped so that they never eat into the reserved space.
+///
+/// Panics if the reserve leaves `Normal` extrinsics less than `AVERAGE_ON_INITIALIZE_RATIO` of
+/// the block.
+pub fn build_block_weights_with_reserve(
+	base_block: Weight,
+	base_extrinsic: Weight,
+	operational_reserve: Perbill,
+) -> limits::BlockWeights {
+	let unreserved_ratio = operational_reserve.left_from_one();
+	let normal_ratio = NORMAL_DISPATCH_RATIO.min(unreserved_ratio);
+	assert!(
+		normal_ratio >= AVERAGE_ON_INITIALIZE_RATIO,
+		"operational reserve leaves less than AVERAGE_ON_INITIALIZE_RATIO to normal extrinsics"
+	);
+
+	limits::BlockWeights::builder()
+		.base_block(base_block)
+		.for_class(DispatchClass::all(), |weights| {
+			weights.base_extrinsic = base_extrinsic;
+		})
+		.for_class(DispatchClass::Normal, |weights| {
+			weights.max_total = Some(normal_ratio * MAXIMUM_BLOCK_WEIGHT);
+		})
+		.for_class(DispatchClass::Operational, |weights| {
+			weights.max_total = Some(MAXIMUM_BLOCK_WEIGHT);
+			// Operational transactions have an extra reserved space, so that they
+			// are included even if block reached `MAXIMUM_BLOCK_WEIGHT`.
+			weights.reserved = Some(MAXIMUM_BLOCK_WEIGHT - unreserved_ratio * MAXIMUM_BLOCK_WEIGHT);
+		})
+		.avg_block_initialization(AVERAGE_ON_INITIALIZE_RATIO)
+		.build_or_panic()
+}
+
 /// Implements the weight types for a runtime.
 /// It expects the passed runtime constants to contain a `weights` module.
 /// The generated weight types were formerly part of the common
 /// runtime but are now runtime dependant.
+/// An operational reserve ratio can be passed to override [`OPERATIONAL_RESERVE_RATIO`].
 #[macro_export]
 macro_rules! impl_runtime_weights {
 	($runtime:ident) => {
+		$crate::impl_runtime_weights!($runtime, $crate::OPERATIONAL_RESERVE_RATIO);
+	};
+	($runtime:ident, $operational_reserve:expr) => {
 		use frame_support::{dispatch::DispatchClass, weights::Weight};
 		use frame_system::limits;
 		use pallet_transaction_payment::{Multiplier, TargetedFeeAdjustment};
 		pub use runtime_common::{
-			impl_elections_weights, AVERAGE_ON_INITIALIZE_RATIO, MAXIMUM_BLOCK_WEIGHT,
-			NORMAL_DISPATCH_RATIO,
+			build_block_weights_with_reserve, impl_elections_weights, AVERAGE_ON_INITIALIZE_RATIO,
+			MAXIMUM_BLOCK_WEIGHT, NORMAL_DISPATCH_RATIO,
 		};
 		use sp_runtime::{FixedPointNumber, Perquintill};
 
@@ -116,33 +221,41 @@ macro_rules! impl_runtime_weights {
 
 		parameter_types! {
 			/// Block weights base values and limits.
-			pub BlockWeights: limits::BlockWeights = limits::BlockWeights::builder()
-				.base_block($runtime::weights::BlockExecutionWeight::get())
-				.for_class(DispatchClass::all(), |weights| {
-					weights.base_extrinsic = $runtime::weights::ExtrinsicBaseWeight::get();
-				})
-				.for_class(DispatchClass::Normal, |weights| {
-					weights.max_total = Some(NORMAL_DISPATCH_RATIO * MAXIMUM_BLOCK_WEIGHT);
-				})
-				.for_class(DispatchClass::Operational, |weights| {
-					weights.max_total = Some(MAXIMUM_BLOCK_WEIGHT);
-					// Operational transactions have an extra reserved space, so that they
-					// are included even if block reached `MAXIMUM_BLOCK_WEIGHT`.
-					weights.reserved = Some(
-						MAXIMUM_BLOCK_WEIGHT - NORMAL_DISPATCH_RATIO * MAXIMUM_BLOCK_WEIGHT,
-					);
-				})
-				.avg_block_initialization(AVERAGE_ON_INITIALIZE_RATIO)
-				.build_or_panic();
+			pub BlockWeights: limits::BlockWeights = build_block_weights_with_reserve(
+				$runtime::weights::BlockExecutionWeight::get(),
+				$runtime::weights::ExtrinsicBaseWeight::get(),
+				$operational_reserve,
+			);
 		}
 	};
 }
 
-/// The type used for currency conversion.
+/// Selects the `CurrencyToVote` implementation suited to a balance type.
 ///
-/// This must only be used as long as the balance type is `u128`.
-pub type CurrencyToVote = sp_staking::currency_to_vote::U128CurrencyToVote;
-static_assertions::assert_eq_size!(primitives::Balance, u128);
+/// `u128` balances are scaled down by the issuance, while narrower balances fit into a vote
+/// weight and are converted saturating.
+pub trait BalanceToVote: Sized {
+	/// The currency conversion used for this balance type.
+	type CurrencyToVote: sp_staking::currency_to_vote::CurrencyToVote<Self>;
+}
+
+impl BalanceToVote for u128 {
+	type CurrencyToVote = sp_staking::currency_to_vote::U128CurrencyToVote;
+}
+
+impl BalanceToVote for u64 {
+	type CurrencyToVote = sp_staking::currency_to_vote::SaturatingCurrencyToVote;
+}
+
+impl BalanceToVote for u32 {
+	type CurrencyToVote = sp_staking::currency_to_vote::SaturatingCurrencyToVote;
+}
+
+/// The type used for currency conversion of a given balance type.
+pub type CurrencyToVoteFor<B> = <B as BalanceToVote>::CurrencyToVote;
+
+/// The type used for currency conversion.
+pub type CurrencyToVote = CurrencyToVoteFor<Balance>;
 
 /// A reasonable benchmarking config for staking pallet.
 pub struct StakingBenchmarkingConfig;
@@ -197,3 +310,96 @@ macro_rules! prod_or_fast {
 		}
 	};
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_staking::currency_to_vote::CurrencyToVote as _;
+
+	#[test]
+	fn build_block_weights_works() {
+		let base_block = Weight::from_parts(5_000_000, 0);
+		let base_extrinsic = Weight::from_parts(100_000, 0);
+		let weights = build_block_weights(base_block, base_extrinsic);
+
+		assert_eq!(weights.base_block, base_block);
+		assert_eq!(weights.max_block, MAXIMUM_BLOCK_WEIGHT);
+		for class in DispatchClass::all() {
+			assert_eq!(weights.get(*class).base_extrinsic, base_extrinsic);
+		}
+		assert_eq!(
+			weights.get(DispatchClass::Normal).max_total,
+			Some(NORMAL_DISPATCH_RATIO * MAXIMUM_BLOCK_WEIGHT),
+		);
+		assert_eq!(weights.get(DispatchClass::Operational).max_total, Some(MAXIMUM_BLOCK_WEIGHT));
+		assert_eq!(
+			weights.get(DispatchClass::Operational).reserved,
+			Some(MAXIMUM_BLOCK_WEIGHT - NORMAL_DISPATCH_RATIO * MAXIMUM_BLOCK_WEIGHT),
+		);
+	}
+
+	#[test]
+	fn custom_operational_reserve_works() {
+		let base_block = Weight::from_parts(5_000_000, 0);
+		let base_extrinsic = Weight::from_parts(100_000, 0);
+
+		for reserve in [10, 25, 40].map(Perbill::from_percent) {
+			let weights = build_block_weights_with_reserve(base_block, base_extrinsic, reserve);
+			let normal = weights.get(DispatchClass::Normal).max_total.unwrap();
+			let reserved = weights.get(DispatchClass::Operational).reserved.unwrap();
+
+			assert_eq!(
+				reserved,
+				MAXIMUM_BLOCK_WEIGHT - reserve.left_from_one() * MAXIMUM_BLOCK_WEIGHT
+			);
+			assert_eq!(
+				normal,
+				NORMAL_DISPATCH_RATIO.min(reserve.left_from_one()) * MAXIMUM_BLOCK_WEIGHT
+			);
+			assert!((normal + reserved).all_lte(MAXIMUM_BLOCK_WEIGHT));
+		}
+
+		// The default reserve is what is left over by normal extrinsics.
+		assert_eq!(OPERATIONAL_RESERVE_RATIO.left_from_one(), NORMAL_DISPATCH_RATIO);
+
+		// A reserve leaving normal extrinsics less than the average `on_initialize` is rejected.
+		let too_large =
+			Perbill::from_parts(AVERAGE_ON_INITIALIZE_RATIO.left_from_one().deconstruct() + 1);
+		let panic = std::panic::catch_unwind(|| {
+			build_block_weights_with_reserve(base_block, base_extrinsic, too_large)
+		})
+		.unwrap_err();
+		assert_eq!(
+			panic.downcast_ref::<&str>(),
+			Some(&"operational reserve leaves less than AVERAGE_ON_INITIALIZE_RATIO to normal extrinsics")
+		);
+	}
+
+	#[test]
+	fn multiplier_can_grow_from_zero() {
+		crate::mock::new_test_ext().execute_with(|| {
+			assert_multiplier_recovers_from_min::<
+				crate::mock::Test,
+				SlowAdjustingFeeUpdate<crate::mock::Test>,
+			>(MinimumMultiplier::get(), MaximumMultiplier::get(), 100);
+		});
+	}
+
+	#[test]
+	fn currency_to_vote_supports_non_u128_balance() {
+		type NarrowBalance = u64;
+
+		assert_eq!(CurrencyToVoteFor::<NarrowBalance>::to_vote(1_000, 10_000), 1_000);
+		assert_eq!(CurrencyToVoteFor::<NarrowBalance>::to_currency(1_000, 10_000), 1_000);
+		assert_eq!(CurrencyToVoteFor::<NarrowBalance>::to_currency(u128::MAX, 10_000), u64::MAX);
+	}
+
+	#[test]
+	fn currency_to_vote_defaults_to_u128_conversion() {
+		let issuance = u128::from(u64::MAX) * 4;
+		assert_eq!(
+			CurrencyToVote::to_vote(issuance, issuance),
+			sp_staking::currency_to_vote::U128CurrencyToVote::to_vote(issuance, issuance),
+		);
+	}
+}
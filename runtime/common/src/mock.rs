@@ -0,0 +1,192 @@
+// Copyright 2022 Smallworld Selendra
+// This file is part of Selendra.
+
+// Selendra is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Selendra is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Selendra.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Mock runtime for the tests of this crate.
+
+use frame_support::{
+	parameter_types,
+	traits::{ConstU32, ConstU64, FindAuthor, UnixTime, WithdrawReasons},
+	PalletId,
+};
+use primitives::AccountId;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, ConvertInto, IdentityLookup},
+	BuildStorage, Permill,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+pub const TEST_ACCOUNT: AccountId = AccountId::new([1; 32]);
+
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Authorship: pallet_authorship,
+		Balances: pallet_balances,
+		Treasury: pallet_treasury,
+		TreasuryBurn: crate::treasury_burn,
+		Vesting: pallet_vesting,
+		Staking: pallet_staking,
+	}
+);
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type Balance = u64;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type WeightInfo = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type FreezeIdentifier = ();
+	type MaxHolds = ConstU32<0>;
+	type MaxFreezes = ConstU32<0>;
+}
+
+parameter_types! {
+	pub const TreasuryPalletId: PalletId = PalletId(*b"sel/trsy");
+	pub const SpendPeriod: u64 = 2;
+	pub const DefaultBurn: Permill = Permill::from_percent(1);
+}
+
+impl pallet_treasury::Config for Test {
+	type PalletId = TreasuryPalletId;
+	type Currency = Balances;
+	type ApproveOrigin = frame_system::EnsureRoot<AccountId>;
+	type RejectOrigin = frame_system::EnsureRoot<AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type OnSlash = ();
+	type ProposalBond = ();
+	type ProposalBondMinimum = ();
+	type ProposalBondMaximum = ();
+	type SpendPeriod = SpendPeriod;
+	type Burn = TreasuryBurn;
+	type BurnDestination = ();
+	type SpendFunds = ();
+	type MaxApprovals = ConstU32<100>;
+	type WeightInfo = ();
+	type SpendOrigin = frame_support::traits::NeverEnsureOrigin<u64>;
+}
+
+impl crate::treasury_burn::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type BurnOrigin = frame_system::EnsureRoot<AccountId>;
+	type DefaultBurn = DefaultBurn;
+}
+
+parameter_types! {
+	pub UnvestedFundsAllowedWithdrawReasons: WithdrawReasons =
+		WithdrawReasons::except(WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE);
+}
+
+impl pallet_vesting::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type BlockNumberToBalance = ConvertInto;
+	type MinVestedTransfer = ConstU64<1>;
+	type WeightInfo = ();
+	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
+	const MAX_VESTING_SCHEDULES: u32 = 28;
+}
+
+pub struct GenesisTime;
+impl UnixTime for GenesisTime {
+	fn now() -> core::time::Duration {
+		Default::default()
+	}
+}
+
+type NoElection =
+	frame_election_provider_support::NoElection<(AccountId, u64, Staking, ConstU32<100>)>;
+
+impl pallet_staking::Config for Test {
+	type MaxNominations = ConstU32<16>;
+	type Currency = Balances;
+	type CurrencyBalance = u64;
+	type UnixTime = GenesisTime;
+	type CurrencyToVote = crate::CurrencyToVoteFor<u64>;
+	type RewardRemainder = ();
+	type RuntimeEvent = RuntimeEvent;
+	type Slash = ();
+	type Reward = ();
+	type SessionsPerEra = ConstU32<3>;
+	type BondingDuration = ConstU32<3>;
+	type SlashDeferDuration = ConstU32<0>;
+	type AdminOrigin = frame_system::EnsureRoot<AccountId>;
+	type SessionInterface = ();
+	type EraPayout = ();
+	type MaxNominatorRewardedPerValidator = ConstU32<64>;
+	type OffendingValidatorsThreshold = ();
+	type NextNewSession = ();
+	type ElectionProvider = NoElection;
+	type GenesisElectionProvider = NoElection;
+	type VoterList = pallet_staking::UseNominatorsAndValidatorsMap<Self>;
+	type TargetList = pallet_staking::UseValidatorsMap<Self>;
+	type MaxUnlockingChunks = ConstU32<32>;
+	type HistoryDepth = ConstU32<84>;
+	type BenchmarkingConfig = crate::StakingBenchmarkingConfig;
+	type EventListeners = ();
+	type WeightInfo = ();
+}
+
+pub struct OneAuthor;
+impl FindAuthor<AccountId> for OneAuthor {
+	fn find_author<'a, I>(_: I) -> Option<AccountId>
+	where
+		I: 'a,
+	{
+		Some(TEST_ACCOUNT)
+	}
+}
+
+impl pallet_authorship::Config for Test {
+	type FindAuthor = OneAuthor;
+	type EventHandler = ();
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into()
+}
@@ -0,0 +1,256 @@
+// Copyright 2022 Smallworld Selendra
+// This file is part of Selendra.
+
+// Selendra is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Selendra is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Selendra.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Composite origins shared by the Selendra runtimes.
+
+use frame_support::traits::{EnsureOrigin, Get};
+use frame_system::RawOrigin;
+use sp_std::marker::PhantomData;
+
+/// `EnsureOrigin` implementation that succeeds if at least two of the three sub-origins `A`, `B`
+/// and `C` would succeed for the same origin.
+///
+/// Useful for thresholds across several governance bodies, where `EitherOfDiverse` is too weak.
+///
+/// For benchmarking, a successful origin is only found if the successful origin of one sub-origin
+/// is also accepted by another one. For disjoint bodies there is no such origin, so benchmarks of
+/// calls gated by this type fail unless the sub-origins share one (e.g. all accepting `Root`).
+pub struct EnsureTwoOfThree<A, B, C>(PhantomData<(A, B, C)>);
+impl<OuterOrigin, A, B, C> EnsureOrigin<OuterOrigin> for EnsureTwoOfThree<A, B, C>
+where
+	OuterOrigin: Clone,
+	A: EnsureOrigin<OuterOrigin>,
+	B: EnsureOrigin<OuterOrigin>,
+	C: EnsureOrigin<OuterOrigin>,
+{
+	type Success = ();
+
+	fn try_origin(o: OuterOrigin) -> Result<Self::Success, OuterOrigin> {
+		let approvals = [
+			A::try_origin(o.clone()).is_ok(),
+			B::try_origin(o.clone()).is_ok(),
+			C::try_origin(o.clone()).is_ok(),
+		]
+		.iter()
+		.filter(|approved| **approved)
+		.count();
+
+		if approvals >= 2 {
+			Ok(())
+		} else {
+			Err(o)
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<OuterOrigin, ()> {
+		// Any successful origin of a sub-origin will do, as long as it also satisfies another one.
+		// Origins of distinct sub-origins can't be combined, so disjoint bodies have none.
+		[A::try_successful_origin(), B::try_successful_origin(), C::try_successful_origin()]
+			.into_iter()
+			.filter_map(Result::ok)
+			.find(|o| Self::try_origin(o.clone()).is_ok())
+			.ok_or(())
+	}
+}
+
+/// `EnsureOrigin` implementation that succeeds for signed origins whose account is a stash with
+/// at least `MinStake` actively bonded in `pallet_staking`.
+///
+/// Gives privileged calls a requirement of skin in the game. Succeeds with the stash account.
+pub struct EnsureStaked<T, MinStake>(PhantomData<(T, MinStake)>);
+impl<T, MinStake> EnsureStaked<T, MinStake>
+where
+	T: pallet_staking::Config,
+	MinStake: Get<pallet_staking::BalanceOf<T>>,
+{
+	fn has_min_stake(stash: &T::AccountId) -> bool {
+		pallet_staking::Pallet::<T>::bonded(stash)
+			.and_then(|controller| pallet_staking::Pallet::<T>::ledger(controller))
+			.map_or(false, |ledger| ledger.active >= MinStake::get())
+	}
+}
+
+impl<T, MinStake> EnsureOrigin<T::RuntimeOrigin> for EnsureStaked<T, MinStake>
+where
+	T: pallet_staking::Config,
+	MinStake: Get<pallet_staking::BalanceOf<T>>,
+{
+	type Success = T::AccountId;
+
+	fn try_origin(o: T::RuntimeOrigin) -> Result<Self::Success, T::RuntimeOrigin> {
+		o.into().and_then(|o| match o {
+			RawOrigin::Signed(who) if Self::has_min_stake(&who) => Ok(who),
+			r => Err(T::RuntimeOrigin::from(r)),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<T::RuntimeOrigin, ()> {
+		use frame_support::traits::Currency;
+		use sp_runtime::traits::Saturating;
+
+		let stash: T::AccountId = frame_benchmarking::account("staked", 0, 0);
+		let amount = MinStake::get().max(T::Currency::minimum_balance());
+		T::Currency::make_free_balance_be(&stash, amount.saturating_mul(2u32.into()));
+		pallet_staking::Pallet::<T>::bond(
+			RawOrigin::Signed(stash.clone()).into(),
+			amount,
+			pallet_staking::RewardDestination::Staked,
+		)
+		.map_err(|_| ())?;
+
+		Ok(RawOrigin::Signed(stash).into())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::*;
+	use frame_support::parameter_types;
+	use primitives::AccountId;
+
+	/// Test origin carrying a bitmask of the bodies that approved it.
+	#[derive(Clone, Debug, PartialEq)]
+	struct Approvals(u8);
+
+	struct EnsureBody<const BIT: u8>;
+	impl<const BIT: u8> EnsureOrigin<Approvals> for EnsureBody<BIT> {
+		type Success = ();
+
+		fn try_origin(o: Approvals) -> Result<Self::Success, Approvals> {
+			if o.0 & (1 << BIT) != 0 {
+				Ok(())
+			} else {
+				Err(o)
+			}
+		}
+
+		#[cfg(feature = "runtime-benchmarks")]
+		fn try_successful_origin() -> Result<Approvals, ()> {
+			Ok(Approvals(1 << BIT))
+		}
+	}
+
+	/// Requires the approval of all the bodies in `MASK`.
+	#[cfg(feature = "runtime-benchmarks")]
+	struct EnsureBodies<const MASK: u8>;
+	#[cfg(feature = "runtime-benchmarks")]
+	impl<const MASK: u8> EnsureOrigin<Approvals> for EnsureBodies<MASK> {
+		type Success = ();
+
+		fn try_origin(o: Approvals) -> Result<Self::Success, Approvals> {
+			if o.0 & MASK == MASK {
+				Ok(())
+			} else {
+				Err(o)
+			}
+		}
+
+		fn try_successful_origin() -> Result<Approvals, ()> {
+			Ok(Approvals(MASK))
+		}
+	}
+
+	type TwoOfThree = EnsureTwoOfThree<EnsureBody<0>, EnsureBody<1>, EnsureBody<2>>;
+
+	parameter_types! {
+		pub const MinStake: u64 = 100;
+	}
+
+	type Staked = EnsureStaked<Test, MinStake>;
+
+	const STASH: AccountId = AccountId::new([2; 32]);
+	const CONTROLLER: AccountId = AccountId::new([3; 32]);
+
+	/// Bond `active` from `stash`, controlled by `controller`.
+	fn bond(stash: AccountId, controller: AccountId, active: u64) {
+		pallet_staking::Bonded::<Test>::insert(&stash, &controller);
+		pallet_staking::Ledger::<Test>::insert(
+			controller,
+			pallet_staking::StakingLedger {
+				stash,
+				total: active,
+				active,
+				unlocking: Default::default(),
+				claimed_rewards: Default::default(),
+			},
+		);
+	}
+
+	#[test]
+	fn any_pair_of_approvals_passes() {
+		assert!(TwoOfThree::try_origin(Approvals(0b011)).is_ok());
+		assert!(TwoOfThree::try_origin(Approvals(0b101)).is_ok());
+		assert!(TwoOfThree::try_origin(Approvals(0b110)).is_ok());
+		assert!(TwoOfThree::try_origin(Approvals(0b111)).is_ok());
+	}
+
+	#[test]
+	fn single_approval_fails() {
+		assert_eq!(TwoOfThree::try_origin(Approvals(0b000)), Err(Approvals(0b000)));
+		assert_eq!(TwoOfThree::try_origin(Approvals(0b001)), Err(Approvals(0b001)));
+		assert_eq!(TwoOfThree::try_origin(Approvals(0b010)), Err(Approvals(0b010)));
+		assert_eq!(TwoOfThree::try_origin(Approvals(0b100)), Err(Approvals(0b100)));
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	#[test]
+	fn successful_origin_needs_an_origin_shared_by_two_bodies() {
+		// Bodies 0 and 1 can jointly approve, which also satisfies body 1 on its own.
+		type Joint = EnsureTwoOfThree<EnsureBodies<0b011>, EnsureBody<1>, EnsureBody<2>>;
+		let origin = Joint::try_successful_origin().unwrap();
+		assert_eq!(origin, Approvals(0b011));
+		assert!(Joint::try_origin(origin).is_ok());
+
+		// Disjoint bodies have no origin approved by two of them.
+		assert_eq!(TwoOfThree::try_successful_origin(), Err(()));
+	}
+
+	#[test]
+	fn sufficiently_staked_stash_passes() {
+		new_test_ext().execute_with(|| {
+			bond(TEST_ACCOUNT, TEST_ACCOUNT, 100);
+			assert_eq!(
+				Staked::try_origin(RuntimeOrigin::signed(TEST_ACCOUNT)).ok(),
+				Some(TEST_ACCOUNT)
+			);
+
+			bond(STASH, CONTROLLER, 150);
+			assert_eq!(Staked::try_origin(RuntimeOrigin::signed(STASH)).ok(), Some(STASH));
+		});
+	}
+
+	#[test]
+	fn under_staked_or_unbonded_account_fails() {
+		new_test_ext().execute_with(|| {
+			assert!(Staked::try_origin(RuntimeOrigin::signed(TEST_ACCOUNT)).is_err());
+
+			bond(TEST_ACCOUNT, TEST_ACCOUNT, 99);
+			assert!(Staked::try_origin(RuntimeOrigin::signed(TEST_ACCOUNT)).is_err());
+			assert!(Staked::try_origin(RuntimeOrigin::root()).is_err());
+		});
+	}
+
+	#[test]
+	fn controller_that_is_not_its_stash_fails() {
+		new_test_ext().execute_with(|| {
+			bond(STASH, CONTROLLER, 1_000);
+			assert!(Staked::try_origin(RuntimeOrigin::signed(CONTROLLER)).is_err());
+		});
+	}
+}
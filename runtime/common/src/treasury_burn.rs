@@ -0,0 +1,189 @@
+// Copyright 2022 Smallworld Selendra
+// This file is part of Selendra.
+
+// Selendra is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Selendra is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Selendra.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Governance-controlled burn rate for `pallet_treasury`.
+//!
+//! The pallet is a `Get<Permill>` and is meant to be used as the treasury's `Burn` parameter, so
+//! that the share of unspent funds burnt at the end of each spend period can be changed without a
+//! runtime upgrade.
+
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::Permill;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin allowed to change the burn rate.
+		type BurnOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The burn rate used until governance sets one.
+		#[pallet::constant]
+		type DefaultBurn: Get<Permill>;
+	}
+
+	#[pallet::type_value]
+	pub fn DefaultBurnRate<T: Config>() -> Permill {
+		T::DefaultBurn::get()
+	}
+
+	/// The share of unspent treasury funds burnt at the end of each spend period.
+	#[pallet::storage]
+	#[pallet::getter(fn burn_rate)]
+	pub type BurnRate<T: Config> = StorageValue<_, Permill, ValueQuery, DefaultBurnRate<T>>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The treasury burn rate was changed.
+		BurnRateSet { burn: Permill },
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the share of unspent treasury funds burnt at the end of each spend period.
+		///
+		/// The dispatch origin must be `BurnOrigin`. A `Permill` above 100% does not decode, so a
+		/// call carrying one is rejected before dispatch.
+		#[pallet::call_index(0)]
+		// `BurnRate` write, plus the event deposit reading and writing `EventCount` and appending
+		// to `Events`.
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 3))]
+		pub fn set_burn_rate(origin: OriginFor<T>, burn: Permill) -> DispatchResult {
+			T::BurnOrigin::ensure_origin(origin)?;
+
+			BurnRate::<T>::put(burn);
+			Self::deposit_event(Event::BurnRateSet { burn });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Get<Permill> for Pallet<T> {
+		fn get() -> Permill {
+			Self::burn_rate()
+		}
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking {
+	use super::*;
+	use frame_benchmarking::{benchmarks, BenchmarkError};
+	use frame_support::traits::EnsureOrigin;
+	use sp_runtime::Permill;
+
+	benchmarks! {
+		set_burn_rate {
+			let origin =
+				T::BurnOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+			let burn = Permill::from_percent(50);
+		}: _<T::RuntimeOrigin>(origin, burn)
+		verify {
+			assert_eq!(BurnRate::<T>::get(), burn);
+		}
+
+		impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::*;
+	use frame_support::{
+		assert_noop, assert_ok,
+		traits::{Currency, OnInitialize},
+	};
+	use parity_scale_codec::{Decode, Encode};
+	use sp_runtime::{traits::BadOrigin, Permill};
+
+	/// Fund the treasury pot with `pot`, run the end of a spend period and return the amount burnt.
+	fn run_spend_period(pot: u64) -> u64 {
+		// The pot is the free balance above the existential deposit.
+		Balances::make_free_balance_be(&Treasury::account_id(), pot + 1);
+		let issuance = Balances::total_issuance();
+		Treasury::on_initialize(SpendPeriod::get());
+		issuance - Balances::total_issuance()
+	}
+
+	#[test]
+	fn default_burn_rate_is_used_until_set() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(TreasuryBurn::burn_rate(), DefaultBurn::get());
+			assert_eq!(run_spend_period(1000), 10);
+		});
+	}
+
+	#[test]
+	fn burn_rate_changes_amount_burnt() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(TreasuryBurn::set_burn_rate(
+				RuntimeOrigin::root(),
+				Permill::from_percent(10)
+			));
+			System::assert_last_event(
+				Event::BurnRateSet { burn: Permill::from_percent(10) }.into(),
+			);
+			assert_eq!(run_spend_period(1000), 100);
+
+			assert_ok!(TreasuryBurn::set_burn_rate(RuntimeOrigin::root(), Permill::zero()));
+			assert_eq!(run_spend_period(1000), 0);
+
+			assert_ok!(TreasuryBurn::set_burn_rate(RuntimeOrigin::root(), Permill::one()));
+			assert_eq!(run_spend_period(1000), 1000);
+		});
+	}
+
+	#[test]
+	fn set_burn_rate_requires_burn_origin() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				TreasuryBurn::set_burn_rate(
+					RuntimeOrigin::signed(TEST_ACCOUNT),
+					Permill::from_percent(10)
+				),
+				BadOrigin
+			);
+			assert_eq!(TreasuryBurn::burn_rate(), DefaultBurn::get());
+		});
+	}
+
+	#[test]
+	fn burn_rate_above_hundred_percent_does_not_decode() {
+		let encode = |parts: u32| {
+			let mut call = 0u8.encode();
+			call.extend(parts.encode());
+			call
+		};
+
+		assert_eq!(
+			Call::<Test>::decode(&mut &encode(1_000_000)[..]),
+			Ok(Call::set_burn_rate { burn: Permill::one() })
+		);
+		assert!(Call::<Test>::decode(&mut &encode(1_000_001)[..]).is_err());
+	}
+}
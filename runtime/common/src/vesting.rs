@@ -0,0 +1,116 @@
+// Copyright 2022 Smallworld Selendra
+// This file is part of Selendra.
+
+// Selendra is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Selendra is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Selendra.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Helpers for querying `pallet_vesting` state.
+
+use frame_support::traits::Currency;
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::{One, Saturating, Zero};
+
+type BalanceOf<T> = <<T as pallet_vesting::Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::Balance;
+
+/// The soonest future block at which the claimable vested amount of `who` increases, along with
+/// the amount unlocked at that block across all of the account's schedules.
+///
+/// Schedules unlock linearly after their starting block, so each still-locked schedule unlocks
+/// again at `max(now, starting_block) + 1`. Returns `None` for fully-vested accounts.
+pub fn next_unlock<T: pallet_vesting::Config>(
+	who: &T::AccountId,
+) -> Option<(BlockNumberFor<T>, BalanceOf<T>)> {
+	let schedules = pallet_vesting::Pallet::<T>::vesting(who)?;
+	let now = frame_system::Pallet::<T>::block_number();
+
+	let next = schedules
+		.iter()
+		.filter(|s| !s.locked_at::<T::BlockNumberToBalance>(now).is_zero())
+		.map(|s| now.max(s.starting_block()).saturating_add(One::one()))
+		.min()?;
+
+	let amount = schedules.iter().fold(Zero::zero(), |acc: BalanceOf<T>, s| {
+		acc.saturating_add(
+			s.locked_at::<T::BlockNumberToBalance>(now)
+				.saturating_sub(s.locked_at::<T::BlockNumberToBalance>(next)),
+		)
+	});
+
+	Some((next, amount))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::*;
+	use pallet_vesting::VestingInfo;
+
+	/// Replace the schedules of `TEST_ACCOUNT` with `(locked, per_block, starting_block)` tuples.
+	fn set_schedules(schedules: &[(u64, u64, u64)]) {
+		let schedules = schedules
+			.iter()
+			.map(|&(locked, per_block, start)| VestingInfo::new(locked, per_block, start))
+			.collect::<Vec<_>>();
+		pallet_vesting::Vesting::<Test>::mutate(TEST_ACCOUNT, |v| {
+			*v = Some(schedules.try_into().unwrap())
+		});
+	}
+
+	#[test]
+	fn not_yet_started_schedule_unlocks_after_start() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			set_schedules(&[(100, 10, 10)]);
+
+			assert_eq!(next_unlock::<Test>(&TEST_ACCOUNT), Some((11, 10)));
+		});
+	}
+
+	#[test]
+	fn schedules_with_different_starts_and_rates_are_combined() {
+		new_test_ext().execute_with(|| {
+			set_schedules(&[(100, 10, 0), (60, 3, 8)]);
+
+			// Only the first schedule has started.
+			System::set_block_number(5);
+			assert_eq!(next_unlock::<Test>(&TEST_ACCOUNT), Some((6, 10)));
+
+			// Both schedules unlock at the next block.
+			System::set_block_number(8);
+			assert_eq!(next_unlock::<Test>(&TEST_ACCOUNT), Some((9, 13)));
+		});
+	}
+
+	#[test]
+	fn schedule_fully_vesting_at_next_unlocks_remainder() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(2);
+			set_schedules(&[(25, 10, 0)]);
+
+			assert_eq!(next_unlock::<Test>(&TEST_ACCOUNT), Some((3, 5)));
+		});
+	}
+
+	#[test]
+	fn fully_vested_account_has_no_unlock() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(10);
+			assert_eq!(next_unlock::<Test>(&TEST_ACCOUNT), None);
+
+			set_schedules(&[(25, 10, 0)]);
+			assert_eq!(next_unlock::<Test>(&TEST_ACCOUNT), None);
+		});
+	}
+}
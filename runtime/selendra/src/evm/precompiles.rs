@@ -102,8 +102,8 @@ where
 			a if a == hash(20483) => Some(SubstrateEcdsaPrecompile::<R>::execute(handle)),
 			// Batch 0x5006
 			a if a == hash(20486) => Some(BatchPrecompile::<R>::execute(handle)),
-			// If the address matches asset prefix, the we route through the asset precompile set
-			a if &a.to_fixed_bytes()[0..4] == ASSET_PRECOMPILE_ADDRESS_PREFIX =>
+			// If the address maps to an asset, the we route through the asset precompile set
+			a if R::address_to_asset_id(a).is_some() =>
 				Erc20AssetsPrecompileSet::<R>::new().execute(handle),
 			// Default
 			_ => None,
@@ -49,8 +49,8 @@ use pallet_session::historical as session_historical;
 use pallet_transaction_payment::{FeeDetails, RuntimeDispatchInfo};
 use parity_scale_codec::{Compact, Decode, Encode};
 use primitives::{
-	assets::EvmRevertCodeHandler, AccountId, AccountIndex, AssetId, Balance, BlockNumber, Hash,
-	Moment, Nonce, Signature,
+	assets::{AddressRangeToAssetId, EvmRevertCodeHandler},
+	AccountId, AccountIndex, AssetId, Balance, BlockNumber, Hash, Moment, Nonce, Signature,
 };
 use sp_core::{OpaqueMetadata, H160, H256, U256};
 use sp_runtime::{
@@ -647,7 +647,7 @@ impl pallet_treasury::Config for Runtime {
 	type ProposalBondMinimum = ProposalBondMinimum;
 	type ProposalBondMaximum = ProposalBondMaximum;
 	type SpendPeriod = SpendPeriod;
-	type Burn = Burn;
+	type Burn = TreasuryBurn;
 	type BurnDestination = ();
 	type SpendFunds = Bounties;
 	type MaxApprovals = MaxApprovals;
@@ -655,6 +655,12 @@ impl pallet_treasury::Config for Runtime {
 	type SpendOrigin = TreasurySpender;
 }
 
+impl runtime_common::treasury_burn::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type BurnOrigin = EitherOfDiverse<EnsureRoot<AccountId>, Treasurer>;
+	type DefaultBurn = Burn;
+}
+
 parameter_types! {
 	pub const BountyDepositBase: Balance = 1 * DOLLARS;
 	pub const BountyDepositPayoutDelay: BlockNumber = 8 * DAYS;
@@ -858,7 +864,9 @@ impl pallet_recovery::Config for Runtime {
 	type RecoveryDeposit = RecoveryDeposit;
 }
 
-impl AddressToAssetId<AssetId> for Runtime {
+/// Maps an asset id to the EVM address made of the asset precompile prefix followed by the id.
+pub struct PrefixedAssetAddress;
+impl AddressToAssetId<AssetId> for PrefixedAssetAddress {
 	fn address_to_asset_id(address: H160) -> Option<AssetId> {
 		let mut data = [0u8; 16];
 		let address_bytes: [u8; 20] = address.into();
@@ -878,7 +886,30 @@ impl AddressToAssetId<AssetId> for Runtime {
 	}
 }
 
+/// The asset address mapping, shared by the assets precompile and the revert code handler.
+impl AddressToAssetId<AssetId> for Runtime {
+	fn address_to_asset_id(address: H160) -> Option<AssetId> {
+		AddressRangeToAssetId::<PrefixedAssetAddress, AssetAddressRange>::address_to_asset_id(
+			address,
+		)
+	}
+
+	fn asset_id_to_address(asset_id: AssetId) -> H160 {
+		AddressRangeToAssetId::<PrefixedAssetAddress, AssetAddressRange>::asset_id_to_address(
+			asset_id,
+		)
+	}
+}
+
 parameter_types! {
+	/// EVM addresses reserved for the assets precompile, i.e. the addresses of all asset ids.
+	///
+	/// Narrowing it takes existing assets outside of the new range away from the precompile, so
+	/// on-chain asset ids must be audited first.
+	pub AssetAddressRange: (H160, H160) = (
+		PrefixedAssetAddress::asset_id_to_address(AssetId::MIN),
+		PrefixedAssetAddress::asset_id_to_address(AssetId::MAX),
+	);
 	pub const AssetDeposit: Balance = 10 * DOLLARS;
 	pub const AssetsStringLimit: u32 = 50;
 	/// Key = 32 bytes, Value = 36 bytes (32+1+1+1+1)
@@ -996,6 +1027,7 @@ construct_runtime! {
 		Referenda: pallet_referenda = 32,
 		Whitelist: pallet_whitelist = 33,
 		Origins: pallet_custom_origins = 34,
+		TreasuryBurn: runtime_common::treasury_burn = 35,
 
 		Bounties: pallet_bounties = 41,
 		ChildBounties: pallet_child_bounties = 42,
@@ -1194,6 +1226,8 @@ mod benches {
 		[pallet_referenda, Referenda]
 		[pallet_whitelist, Whitelist]
 		[pallet_assets, Assets]
+		// Selendra
+		[runtime_common::treasury_burn, TreasuryBurn]
 	);
 }
 
@@ -1453,6 +1487,12 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl primitives::VestingApi<Block> for Runtime {
+		fn vesting_next_unlock(who: AccountId) -> Option<(BlockNumber, Balance)> {
+			runtime_common::vesting::next_unlock::<Runtime>(&who)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
 		Block,
 		Balance,
@@ -1810,3 +1850,23 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use runtime_common::{MaximumMultiplier, MinimumMultiplier};
+
+	#[test]
+	fn multiplier_can_grow_from_zero() {
+		let mut ext: sp_io::TestExternalities = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.unwrap()
+			.into();
+		ext.execute_with(|| {
+			runtime_common::assert_multiplier_recovers_from_min::<
+				Runtime,
+				<Runtime as pallet_transaction_payment::Config>::FeeMultiplierUpdate,
+			>(MinimumMultiplier::get(), MaximumMultiplier::get(), 100);
+		});
+	}
+}